@@ -1,27 +1,77 @@
-use rusqlite::{Connection, Result, params};
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::params;
 use std::collections::HashMap;
+use std::fmt;
 use std::path::Path;
+use std::time::Duration;
 use crate::models::*;
 
 const DEFAULT_DB_PATH: &str = "../llmseo.db";
+const MAX_POOL_SIZE: u32 = 8;
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
 
-/// Get database connection
-pub fn get_connection() -> Result<Connection> {
+/// Pooled connection manager type, shared via Rocket state
+pub type DbPool = Pool<SqliteConnectionManager>;
+
+/// Error covering connection checkout failures, query failures, and
+/// malformed category paths
+#[derive(Debug)]
+pub enum DbError {
+    Pool(r2d2::Error),
+    Sqlite(rusqlite::Error),
+    InvalidCategoryPath(String),
+}
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DbError::Pool(e) => write!(f, "connection pool error: {e}"),
+            DbError::Sqlite(e) => write!(f, "sqlite error: {e}"),
+            DbError::InvalidCategoryPath(path) => write!(f, "malformed category path: {path}"),
+        }
+    }
+}
+
+impl std::error::Error for DbError {}
+
+impl From<r2d2::Error> for DbError {
+    fn from(e: r2d2::Error) -> Self {
+        DbError::Pool(e)
+    }
+}
+
+impl From<rusqlite::Error> for DbError {
+    fn from(e: rusqlite::Error) -> Self {
+        DbError::Sqlite(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, DbError>;
+
+/// Build the connection pool used for the lifetime of the server
+pub fn build_pool() -> Result<DbPool> {
     let db_path = std::env::var("LLMSEO_DB_PATH")
         .unwrap_or_else(|_| DEFAULT_DB_PATH.to_string());
-    
+
     // Check if database exists
     if !Path::new(&db_path).exists() {
-        return Err(rusqlite::Error::QueryReturnedNoRows);
+        return Err(DbError::Sqlite(rusqlite::Error::QueryReturnedNoRows));
     }
-    
-    Connection::open(&db_path)
+
+    let manager = SqliteConnectionManager::file(&db_path)
+        .with_init(|conn| conn.busy_timeout(BUSY_TIMEOUT));
+
+    Pool::builder()
+        .max_size(MAX_POOL_SIZE)
+        .build(manager)
+        .map_err(DbError::from)
 }
 
 /// Get dashboard summary statistics
-pub fn get_dashboard_stats() -> Result<DashboardStats> {
-    let conn = get_connection()?;
-    
+pub fn get_dashboard_stats(pool: &DbPool) -> Result<DashboardStats> {
+    let conn = pool.get()?;
+
     // Total brands (from mentions)
     let total_brands: i32 = conn.query_row(
         "SELECT COUNT(DISTINCT brand_name) FROM mentions",
@@ -89,8 +139,8 @@ pub fn get_dashboard_stats() -> Result<DashboardStats> {
 }
 
 /// Get all brand rankings
-pub fn get_brand_rankings() -> Result<Vec<BrandRanking>> {
-    let conn = get_connection()?;
+pub fn get_brand_rankings(pool: &DbPool) -> Result<Vec<BrandRanking>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -124,8 +174,8 @@ pub fn get_brand_rankings() -> Result<Vec<BrandRanking>> {
 }
 
 /// Get provider performance comparison
-pub fn get_provider_performance() -> Result<Vec<ProviderPerformance>> {
-    let conn = get_connection()?;
+pub fn get_provider_performance(pool: &DbPool) -> Result<Vec<ProviderPerformance>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -166,9 +216,84 @@ pub fn get_provider_performance() -> Result<Vec<ProviderPerformance>> {
     Ok(performance)
 }
 
+/// Escape `%` and `_` so `prefix` can be bound into a `LIKE ... ESCAPE '\'`
+/// pattern without those characters being read as wildcards — needed because
+/// `is_valid_path` allows ordinary identifier characters (including `_`) in
+/// category names, so the query itself must neutralize them instead.
+fn escape_like(prefix: &str) -> String {
+    prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// Aggregate brand rankings over an entire category subtree (e.g. `tech.cloud`
+/// rolls up `tech.cloud.storage`, `tech.cloud.compute`, ...) instead of a
+/// single flat category.
+pub fn get_category_rollup(category_prefix: &str, pool: &DbPool) -> Result<CategoryRollup> {
+    if !crate::taxonomy::is_valid_path(category_prefix) {
+        return Err(DbError::InvalidCategoryPath(category_prefix.to_string()));
+    }
+
+    let conn = pool.get()?;
+    let like_prefix = escape_like(category_prefix);
+
+    let (query_count, total_mentions, avg_rank): (i32, i32, Option<f64>) = conn.query_row(
+        "SELECT
+            COUNT(DISTINCT q.id),
+            COUNT(m.id),
+            AVG(m.rank_position)
+         FROM queries q
+         LEFT JOIN responses r ON r.query_id = q.id
+         LEFT JOIN mentions m ON m.response_id = r.id
+         WHERE q.category = ?1 OR q.category LIKE ?2 || '.%' ESCAPE '\\'",
+        params![category_prefix, like_prefix],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+    )?;
+
+    Ok(CategoryRollup {
+        category_prefix: category_prefix.to_string(),
+        query_count,
+        total_mentions,
+        avg_rank,
+    })
+}
+
+/// List every distinct category in use as a `CategoryNode`, with `parent`
+/// derived from the materialized path (the string up to its last `.`
+/// segment), restricted to the subtree under `prefix` (the whole tree if
+/// `prefix` is empty). A category whose derived parent would form a cycle
+/// with it is malformed data and is dropped rather than surfaced.
+pub fn get_category_tree(prefix: &str, pool: &DbPool) -> Result<Vec<CategoryNode>> {
+    if !prefix.is_empty() && !crate::taxonomy::is_valid_path(prefix) {
+        return Err(DbError::InvalidCategoryPath(prefix.to_string()));
+    }
+
+    let conn = pool.get()?;
+    let mut stmt = conn.prepare("SELECT DISTINCT category FROM queries")?;
+    let categories: Vec<String> = stmt
+        .query_map([], |row| row.get(0))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut nodes = Vec::new();
+    for path in categories {
+        let parent = path.rsplit_once('.').map(|(parent, _)| parent.to_string());
+        if let Some(ref parent) = parent {
+            if crate::taxonomy::would_cycle(&path, parent) {
+                continue;
+            }
+        }
+        let label = path.rsplit('.').next().unwrap_or(&path).to_string();
+        nodes.push(CategoryNode { path, label, parent });
+    }
+
+    Ok(crate::taxonomy::descendants(&nodes, prefix)
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
 /// Get competitor graph data
-pub fn get_competitor_graph(min_strength: f64) -> Result<CompetitorGraph> {
-    let conn = get_connection()?;
+pub fn get_competitor_graph(min_strength: f64, pool: &DbPool) -> Result<CompetitorGraph> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -232,8 +357,8 @@ pub fn get_competitor_graph(min_strength: f64) -> Result<CompetitorGraph> {
 }
 
 /// Get top competitors for a specific brand
-pub fn get_brand_competitors(brand_name: &str, top_n: i32) -> Result<Vec<CompetitorRelationship>> {
-    let conn = get_connection()?;
+pub fn get_brand_competitors(brand_name: &str, top_n: i32, pool: &DbPool) -> Result<Vec<CompetitorRelationship>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -271,9 +396,42 @@ pub fn get_brand_competitors(brand_name: &str, top_n: i32) -> Result<Vec<Competi
     Ok(competitors)
 }
 
+/// Get all competitor relationship edges, for traversal (e.g. `graph::expand_cluster`)
+pub fn get_all_competitor_relationships(pool: &DbPool) -> Result<Vec<CompetitorRelationship>> {
+    let conn = pool.get()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT
+            brand_name_1,
+            brand_name_2,
+            co_mention_count,
+            avg_rank_distance,
+            strength_score,
+            first_seen,
+            last_seen
+         FROM competitor_relationships"
+    )?;
+
+    let relationships = stmt.query_map([], |row| {
+        Ok(CompetitorRelationship {
+            brand_name_1: row.get(0)?,
+            brand_name_2: row.get(1)?,
+            co_mention_count: row.get(2)?,
+            avg_rank_distance: row.get(3)?,
+            strength_score: row.get(4)?,
+            first_seen: row.get(5)?,
+            last_seen: row.get(6)?,
+        })
+    })?
+    .filter_map(|r| r.ok())
+    .collect();
+
+    Ok(relationships)
+}
+
 /// Get hallucination scores
-pub fn get_hallucination_scores() -> Result<Vec<HallucinationScore>> {
-    let conn = get_connection()?;
+pub fn get_hallucination_scores(pool: &DbPool) -> Result<Vec<HallucinationScore>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -311,8 +469,8 @@ pub fn get_hallucination_scores() -> Result<Vec<HallucinationScore>> {
 }
 
 /// Get response quality metrics
-pub fn get_response_quality() -> Result<Vec<ResponseQuality>> {
-    let conn = get_connection()?;
+pub fn get_response_quality(pool: &DbPool) -> Result<Vec<ResponseQuality>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -350,8 +508,8 @@ pub fn get_response_quality() -> Result<Vec<ResponseQuality>> {
 }
 
 /// Get sources for a specific mention
-pub fn get_sources_for_mention(mention_id: i64) -> Result<Vec<Source>> {
-    let conn = get_connection()?;
+pub fn get_sources_for_mention(mention_id: i64, pool: &DbPool) -> Result<Vec<Source>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -381,8 +539,8 @@ pub fn get_sources_for_mention(mention_id: i64) -> Result<Vec<Source>> {
 }
 
 /// Get all unique brand names
-pub fn get_all_brands() -> Result<Vec<String>> {
-    let conn = get_connection()?;
+pub fn get_all_brands(pool: &DbPool) -> Result<Vec<String>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT DISTINCT brand_name FROM mentions ORDER BY brand_name"
@@ -396,8 +554,8 @@ pub fn get_all_brands() -> Result<Vec<String>> {
 }
 
 /// Get recent mentions
-pub fn get_recent_mentions(limit: i32) -> Result<Vec<BrandMention>> {
-    let conn = get_connection()?;
+pub fn get_recent_mentions(limit: i32, pool: &DbPool) -> Result<Vec<BrandMention>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -431,8 +589,8 @@ pub fn get_recent_mentions(limit: i32) -> Result<Vec<BrandMention>> {
 }
 
 /// Get LLM evaluation statistics
-pub fn get_evaluation_stats() -> Result<Vec<EvaluationStats>> {
-    let conn = get_connection()?;
+pub fn get_evaluation_stats(pool: &DbPool) -> Result<Vec<EvaluationStats>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -464,8 +622,8 @@ pub fn get_evaluation_stats() -> Result<Vec<EvaluationStats>> {
 }
 
 /// Get match confidence distribution
-pub fn get_confidence_distribution() -> Result<Vec<(String, i32)>> {
-    let conn = get_connection()?;
+pub fn get_confidence_distribution(pool: &DbPool) -> Result<Vec<(String, i32)>> {
+    let conn = pool.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT 
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::models::{CompetitorGraph, CompetitorRelationship, GraphEdge, GraphNode};
+
+/// Upper bound on nodes reached by `expand_cluster`, to avoid runaway
+/// expansion on dense graphs.
+const MAX_CLUSTER_NODES: usize = 200;
+
+/// Expand the transitive competitor neighborhood around `seed` by breadth-first
+/// traversal over `edges`, stopping at `max_depth` hops or `MAX_CLUSTER_NODES`
+/// nodes, whichever comes first.
+///
+/// `brand_name_1`/`brand_name_2` are treated symmetrically, and an edge seen
+/// from both directions is only counted once.
+pub fn expand_cluster(
+    seed: &str,
+    edges: &[CompetitorRelationship],
+    max_depth: u32,
+    min_strength: f64,
+) -> CompetitorGraph {
+    let mut depths: HashMap<String, u32> = HashMap::new();
+    depths.insert(seed.to_string(), 0);
+
+    let mut frontier: VecDeque<(String, u32)> = VecDeque::new();
+    frontier.push_back((seed.to_string(), 0));
+
+    let mut seen_edges: HashSet<(String, String)> = HashSet::new();
+    let mut reached_edges: Vec<&CompetitorRelationship> = Vec::new();
+
+    while let Some((brand, depth)) = frontier.pop_front() {
+        if depth >= max_depth {
+            continue;
+        }
+
+        for edge in edges {
+            if edge.strength_score < min_strength {
+                continue;
+            }
+
+            let (a, b) = (&edge.brand_name_1, &edge.brand_name_2);
+            let neighbor = if a == &brand {
+                b
+            } else if b == &brand {
+                a
+            } else {
+                continue;
+            };
+
+            if !depths.contains_key(neighbor) {
+                if depths.len() >= MAX_CLUSTER_NODES {
+                    // Neighbor was dropped by the node cap — skip the edge
+                    // too, so it never dangles with an endpoint missing
+                    // from `nodes`.
+                    continue;
+                }
+                depths.insert(neighbor.clone(), depth + 1);
+                frontier.push_back((neighbor.clone(), depth + 1));
+            }
+
+            let edge_key = if a <= b {
+                (a.clone(), b.clone())
+            } else {
+                (b.clone(), a.clone())
+            };
+            if seen_edges.insert(edge_key) {
+                reached_edges.push(edge);
+            }
+        }
+    }
+
+    let mut node_strength: HashMap<String, f64> = HashMap::new();
+    for edge in &reached_edges {
+        *node_strength.entry(edge.brand_name_1.clone()).or_insert(0.0) += edge.strength_score;
+        *node_strength.entry(edge.brand_name_2.clone()).or_insert(0.0) += edge.strength_score;
+    }
+
+    let nodes: Vec<GraphNode> = depths
+        .keys()
+        .map(|brand| GraphNode {
+            id: brand.clone(),
+            label: brand.clone(),
+            size: node_strength.get(brand).copied(),
+        })
+        .collect();
+
+    let graph_edges: Vec<GraphEdge> = reached_edges
+        .into_iter()
+        .map(|edge| GraphEdge {
+            source: edge.brand_name_1.clone(),
+            target: edge.brand_name_2.clone(),
+            weight: edge.strength_score,
+            co_mentions: edge.co_mention_count,
+            avg_distance: edge.avg_rank_distance,
+        })
+        .collect();
+
+    let total_nodes = nodes.len();
+    let total_edges = graph_edges.len();
+
+    CompetitorGraph {
+        nodes,
+        edges: graph_edges,
+        total_nodes,
+        total_edges,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(a: &str, b: &str, strength: f64) -> CompetitorRelationship {
+        CompetitorRelationship {
+            brand_name_1: a.to_string(),
+            brand_name_2: b.to_string(),
+            co_mention_count: 1,
+            avg_rank_distance: 0.0,
+            strength_score: strength,
+            first_seen: 0.0,
+            last_seen: 0.0,
+        }
+    }
+
+    #[test]
+    fn treats_brand_name_1_and_2_symmetrically() {
+        let edges = vec![edge("b", "seed", 1.0)];
+        let graph = expand_cluster("seed", &edges, 1, 0.0);
+        assert_eq!(graph.total_nodes, 2);
+        assert_eq!(graph.total_edges, 1);
+    }
+
+    #[test]
+    fn dedupes_edges_seen_from_either_direction() {
+        let edges = vec![edge("seed", "b", 1.0), edge("b", "seed", 1.0)];
+        let graph = expand_cluster("seed", &edges, 1, 0.0);
+        assert_eq!(graph.total_edges, 1);
+    }
+
+    #[test]
+    fn stops_expansion_at_max_depth() {
+        let edges = vec![edge("seed", "a", 1.0), edge("a", "b", 1.0), edge("b", "c", 1.0)];
+        let graph = expand_cluster("seed", &edges, 1, 0.0);
+        assert_eq!(graph.total_nodes, 2); // seed, a
+        assert_eq!(graph.total_edges, 1); // seed-a only
+    }
+
+    #[test]
+    fn filters_edges_below_min_strength() {
+        let edges = vec![edge("seed", "a", 0.1), edge("seed", "b", 0.9)];
+        let graph = expand_cluster("seed", &edges, 1, 0.5);
+        assert_eq!(graph.total_nodes, 2); // seed, b
+        assert_eq!(graph.total_edges, 1);
+    }
+
+    #[test]
+    fn node_cap_never_leaves_dangling_edges() {
+        let edges: Vec<CompetitorRelationship> = (0..300)
+            .map(|i| edge("seed", &format!("n{i}"), 1.0))
+            .collect();
+        let graph = expand_cluster("seed", &edges, 1, 0.0);
+
+        assert_eq!(graph.total_nodes, MAX_CLUSTER_NODES);
+        assert_eq!(graph.total_edges, MAX_CLUSTER_NODES - 1);
+
+        let node_ids: HashSet<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+        for e in &graph.edges {
+            assert!(node_ids.contains(e.source.as_str()), "dangling source {}", e.source);
+            assert!(node_ids.contains(e.target.as_str()), "dangling target {}", e.target);
+        }
+    }
+}
@@ -2,15 +2,22 @@
 extern crate rocket;
 
 mod db;
+mod graph;
 mod models;
+mod parsing;
+mod providers;
 mod routes;
+mod taxonomy;
 
 use rocket::fs::{FileServer, relative};
 use rocket_dyn_templates::Template;
 
 #[launch]
 fn rocket() -> _ {
+    let pool = db::build_pool().expect("failed to build database connection pool");
+
     rocket::build()
+        .manage(pool)
         .attach(Template::fairing())
         .mount("/", routes::index_routes())
         .mount("/api", routes::api_routes())
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+// Dashboard-facing types additionally derive `TS` behind the `ts-bindings`
+// feature, exporting matching `.ts` interfaces when the test suite runs so
+// the frontend contract can't silently drift from these structs.
+
 /// Brand mention from an LLM response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BrandMention {
@@ -22,6 +26,22 @@ pub struct Response {
     pub raw_response: Option<String>,
     pub timestamp: f64,
     pub error_message: Option<String>,
+    /// Whether `raw_response` parsed into the expected ranked-mention schema
+    pub is_structured: bool,
+}
+
+impl Response {
+    /// Parse `raw_response` into structured mentions, falling back to the
+    /// raw payload when the provider's output doesn't match the schema.
+    /// Also updates `is_structured` to reflect which path was taken.
+    pub fn parse_response(&mut self) -> crate::parsing::ParsedResponse {
+        let parsed = match &self.raw_response {
+            Some(raw) => crate::parsing::ParsedResponse::parse(raw),
+            None => crate::parsing::ParsedResponse::Dynamic(serde_json::Value::Null),
+        };
+        self.is_structured = parsed.is_structured();
+        parsed
+    }
 }
 
 /// Brand configuration
@@ -41,6 +61,24 @@ pub struct Query {
     pub category: String,
 }
 
+/// Hierarchical category, modeled as a materialized path (e.g. `tech.cloud.storage`)
+/// so subtree membership is a cheap string-prefix check rather than a join.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryNode {
+    pub path: String,
+    pub label: String,
+    pub parent: Option<String>,
+}
+
+/// Brand-visibility rollup aggregated over every query in a category subtree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryRollup {
+    pub category_prefix: String,
+    pub query_count: i32,
+    pub total_mentions: i32,
+    pub avg_rank: Option<f64>,
+}
+
 /// Brand ranking data for reports
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BrandRanking {
@@ -55,6 +93,8 @@ pub struct BrandRanking {
 
 /// Provider performance metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct ProviderPerformance {
     pub provider: String,
     pub model: String,
@@ -94,6 +134,8 @@ pub struct CompetitorRelationship {
 
 /// Graph node for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct GraphNode {
     pub id: String,
     pub label: String,
@@ -102,6 +144,8 @@ pub struct GraphNode {
 
 /// Graph edge for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct GraphEdge {
     pub source: String,
     pub target: String,
@@ -112,6 +156,8 @@ pub struct GraphEdge {
 
 /// Complete graph data for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct CompetitorGraph {
     pub nodes: Vec<GraphNode>,
     pub edges: Vec<GraphEdge>,
@@ -135,6 +181,8 @@ pub struct Source {
 
 /// Hallucination risk score for a mention
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct HallucinationScore {
     pub id: i64,
     pub mention_id: i64,
@@ -163,6 +211,8 @@ pub struct ResponseQuality {
 
 /// Dashboard summary statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct DashboardStats {
     pub total_brands: i32,
     pub total_responses: i32,
@@ -172,10 +222,15 @@ pub struct DashboardStats {
     pub high_risk_count: i32,
     pub medium_risk_count: i32,
     pub low_risk_count: i32,
+    pub llm_match_count: i32,
+    pub regex_match_count: i32,
+    pub avg_match_confidence: f64,
 }
 
 /// Temporal graph snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, export_to = "bindings/"))]
 pub struct TemporalSnapshot {
     pub window_start: String,
     pub window_end: String,
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Provider-facing shape of a single ranked brand mention — what a
+/// conformant LLM response actually contains (brand, the alias it used, its
+/// rank, and an explanation). Distinct from the persisted `BrandMention`,
+/// which additionally carries DB-assigned `id`/`brand_id`/`response_id`
+/// fields no provider payload would ever include.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderMention {
+    pub brand_name: String,
+    pub alias_used: String,
+    pub rank_position: i32,
+    pub explanation: Option<String>,
+}
+
+/// Expected shape of a conformant provider payload: a ranked list of
+/// brand mentions with the alias the model used and its explanation.
+#[derive(Debug, Clone, Deserialize)]
+struct RankedMentions {
+    mentions: Vec<ProviderMention>,
+}
+
+/// Result of parsing `Response.raw_response` against the expected schema.
+///
+/// When a provider's output matches the expected ranked-brand-list shape,
+/// it comes back `Structured`. When it doesn't — a new model, a prompt
+/// change, a provider returning prose instead of JSON — the raw payload is
+/// kept as `Dynamic` instead of being discarded, so it can be re-parsed
+/// later once the schema catches up.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum ParsedResponse {
+    Structured(Vec<ProviderMention>),
+    Dynamic(Value),
+}
+
+impl ParsedResponse {
+    /// Parse a raw provider payload, falling back to `Dynamic` on any
+    /// schema mismatch rather than returning an error.
+    pub fn parse(raw: &str) -> ParsedResponse {
+        match serde_json::from_str::<RankedMentions>(raw) {
+            Ok(ranked) => ParsedResponse::Structured(ranked.mentions),
+            Err(_) => match serde_json::from_str::<Value>(raw) {
+                Ok(value) => ParsedResponse::Dynamic(value),
+                Err(_) => ParsedResponse::Dynamic(Value::String(raw.to_string())),
+            },
+        }
+    }
+
+    /// `true` if the payload matched the expected schema.
+    pub fn is_structured(&self) -> bool {
+        matches!(self, ParsedResponse::Structured(_))
+    }
+
+    /// The parsed brand mentions, if the payload matched the expected schema.
+    pub fn mentions(&self) -> Option<&[ProviderMention]> {
+        match self {
+            ParsedResponse::Structured(mentions) => Some(mentions),
+            ParsedResponse::Dynamic(_) => None,
+        }
+    }
+
+    /// The raw JSON payload, if the schema didn't match.
+    pub fn raw(&self) -> Option<&Value> {
+        match self {
+            ParsedResponse::Structured(_) => None,
+            ParsedResponse::Dynamic(value) => Some(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_ranked_mentions_as_structured() {
+        let raw = r#"{"mentions":[{"brand_name":"Acme","alias_used":"Acme Corp","rank_position":1,"explanation":"top pick"}]}"#;
+        let parsed = ParsedResponse::parse(raw);
+
+        assert!(parsed.is_structured());
+        let mentions = parsed.mentions().expect("structured mentions");
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].brand_name, "Acme");
+    }
+
+    #[test]
+    fn falls_back_to_dynamic_on_schema_mismatch() {
+        let raw = r#"{"mentions":[{"brand_name":"Acme"}]}"#;
+        let parsed = ParsedResponse::parse(raw);
+
+        assert!(!parsed.is_structured());
+        assert!(parsed.mentions().is_none());
+        assert!(parsed.raw().is_some());
+    }
+
+    #[test]
+    fn falls_back_to_dynamic_on_non_json_prose() {
+        let raw = "I'm not sure which brands are relevant here.";
+        let parsed = ParsedResponse::parse(raw);
+
+        assert!(!parsed.is_structured());
+        assert_eq!(parsed.raw(), Some(&Value::String(raw.to_string())));
+    }
+
+    #[test]
+    fn falls_back_to_dynamic_on_empty_input() {
+        let parsed = ParsedResponse::parse("");
+
+        assert!(!parsed.is_structured());
+        assert_eq!(parsed.raw(), Some(&Value::String(String::new())));
+    }
+}
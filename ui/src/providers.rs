@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::models::{Query, Response};
+
+fn now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+fn error_response(provider_name: &str, model: &str, query: &Query, error_message: String) -> Response {
+    Response {
+        id: 0,
+        provider_name: provider_name.to_string(),
+        model_name: model.to_string(),
+        query_id: Some(query.id),
+        raw_response: None,
+        timestamp: now(),
+        error_message: Some(error_message),
+        is_structured: false,
+    }
+}
+
+/// Turn a completed HTTP call into a `Response`, mapping transport failures
+/// and non-2xx status codes into `error_message` instead of treating the
+/// body as a successful payload.
+async fn finish_response(
+    provider_name: &str,
+    model: &str,
+    query: &Query,
+    result: reqwest::Result<reqwest::Response>,
+) -> Response {
+    let resp = match result {
+        Ok(resp) => resp,
+        Err(e) => return error_response(provider_name, model, query, e.to_string()),
+    };
+
+    let status = resp.status();
+    let text = match resp.text().await {
+        Ok(text) => text,
+        Err(e) => return error_response(provider_name, model, query, e.to_string()),
+    };
+
+    if !status.is_success() {
+        return error_response(provider_name, model, query, format!("HTTP {status}: {text}"));
+    }
+
+    let is_structured = crate::parsing::ParsedResponse::parse(&text).is_structured();
+
+    Response {
+        id: 0,
+        provider_name: provider_name.to_string(),
+        model_name: model.to_string(),
+        query_id: Some(query.id),
+        raw_response: Some(text),
+        timestamp: now(),
+        error_message: None,
+        is_structured,
+    }
+}
+
+/// A client capable of querying a single LLM provider for a benchmark run.
+///
+/// Implementations never return an `Err` from `query` — HTTP failures and
+/// non-2xx responses are mapped into `Response.error_message` so a
+/// `ProviderRegistry` can collect uniformly-shaped records regardless of
+/// which providers succeeded.
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Human-readable provider name, stored on `Response.provider_name`.
+    fn name(&self) -> &str;
+
+    /// The specific model this client targets, stored on `Response.model_name`.
+    fn model(&self) -> &str;
+
+    /// Send `query` to the provider and map the outcome into a `Response`.
+    async fn query(&self, query: &Query) -> Response;
+}
+
+/// OpenAI chat completions client.
+pub struct OpenAiProvider {
+    model: String,
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl OpenAiProvider {
+    pub fn new(model: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self { model: model.into(), api_key: api_key.into(), client: reqwest::Client::new() }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn query(&self, query: &Query) -> Response {
+        let body = serde_json::json!({
+            "model": self.model,
+            "messages": [{"role": "user", "content": query.text}],
+        });
+
+        let result = self
+            .client
+            .post("https://api.openai.com/v1/chat/completions")
+            .bearer_auth(&self.api_key)
+            .json(&body)
+            .send()
+            .await;
+
+        finish_response(self.name(), &self.model, query, result).await
+    }
+}
+
+/// Anthropic Messages API client.
+pub struct AnthropicProvider {
+    model: String,
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl AnthropicProvider {
+    pub fn new(model: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self { model: model.into(), api_key: api_key.into(), client: reqwest::Client::new() }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for AnthropicProvider {
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn query(&self, query: &Query) -> Response {
+        let body = serde_json::json!({
+            "model": self.model,
+            "max_tokens": 1024,
+            "messages": [{"role": "user", "content": query.text}],
+        });
+
+        let result = self
+            .client
+            .post("https://api.anthropic.com/v1/messages")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .json(&body)
+            .send()
+            .await;
+
+        finish_response(self.name(), &self.model, query, result).await
+    }
+}
+
+/// Google Gemini `generateContent` client.
+pub struct GoogleProvider {
+    model: String,
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl GoogleProvider {
+    pub fn new(model: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self { model: model.into(), api_key: api_key.into(), client: reqwest::Client::new() }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for GoogleProvider {
+    fn name(&self) -> &str {
+        "google"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn query(&self, query: &Query) -> Response {
+        let url = format!(
+            "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent?key={}",
+            self.model, self.api_key
+        );
+        let body = serde_json::json!({
+            "contents": [{"parts": [{"text": query.text}]}],
+        });
+
+        let result = self.client.post(&url).json(&body).send().await;
+
+        finish_response(self.name(), &self.model, query, result).await
+    }
+}
+
+/// Fans a single `Query` out to every registered provider concurrently and
+/// collects the resulting `Response` records, so a benchmark run can query
+/// every configured model at once.
+#[derive(Default)]
+pub struct ProviderRegistry {
+    providers: Vec<Box<dyn LlmProvider>>,
+}
+
+impl ProviderRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, provider: Box<dyn LlmProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// Run `query` against every registered provider concurrently.
+    pub async fn query_all(&self, query: &Query) -> Vec<Response> {
+        let queries = self.providers.iter().map(|provider| provider.query(query));
+        join_all(queries).await
+    }
+}
@@ -1,7 +1,8 @@
 use rocket::serde::json::Json;
-use rocket::{Route, routes, get};
+use rocket::{Route, routes, get, State};
 use rocket_dyn_templates::{Template, context};
-use crate::db;
+use crate::db::{self, DbPool};
+use crate::graph;
 use crate::models::*;
 
 // =====================
@@ -9,8 +10,8 @@ use crate::models::*;
 // =====================
 
 #[get("/")]
-pub fn index() -> Template {
-    let stats = db::get_dashboard_stats().unwrap_or_else(|_| DashboardStats {
+pub fn index(pool: &State<DbPool>) -> Template {
+    let stats = db::get_dashboard_stats(pool).unwrap_or_else(|_| DashboardStats {
         total_brands: 0,
         total_responses: 0,
         total_mentions: 0,
@@ -24,8 +25,8 @@ pub fn index() -> Template {
         avg_match_confidence: 0.0,
     });
     
-    let recent_mentions = db::get_recent_mentions(10).unwrap_or_default();
-    let providers = db::get_provider_performance().unwrap_or_default();
+    let recent_mentions = db::get_recent_mentions(10, pool).unwrap_or_default();
+    let providers = db::get_provider_performance(pool).unwrap_or_default();
     
     Template::render("index", context! {
         title: "Dashboard",
@@ -36,9 +37,9 @@ pub fn index() -> Template {
 }
 
 #[get("/brands")]
-pub fn brands_page() -> Template {
-    let rankings = db::get_brand_rankings().unwrap_or_default();
-    let brands = db::get_all_brands().unwrap_or_default();
+pub fn brands_page(pool: &State<DbPool>) -> Template {
+    let rankings = db::get_brand_rankings(pool).unwrap_or_default();
+    let brands = db::get_all_brands(pool).unwrap_or_default();
     
     Template::render("brands", context! {
         title: "Brand Rankings",
@@ -48,15 +49,15 @@ pub fn brands_page() -> Template {
 }
 
 #[get("/competitors")]
-pub fn competitors_page() -> Template {
-    let graph = db::get_competitor_graph(0.0).unwrap_or_else(|_| CompetitorGraph {
+pub fn competitors_page(pool: &State<DbPool>) -> Template {
+    let graph = db::get_competitor_graph(0.0, pool).unwrap_or_else(|_| CompetitorGraph {
         nodes: vec![],
         edges: vec![],
         total_nodes: 0,
         total_edges: 0,
     });
     
-    let brands = db::get_all_brands().unwrap_or_default();
+    let brands = db::get_all_brands(pool).unwrap_or_default();
     
     Template::render("competitors", context! {
         title: "Competitor Graph",
@@ -66,9 +67,9 @@ pub fn competitors_page() -> Template {
 }
 
 #[get("/hallucinations")]
-pub fn hallucinations_page() -> Template {
-    let scores = db::get_hallucination_scores().unwrap_or_default();
-    let quality = db::get_response_quality().unwrap_or_default();
+pub fn hallucinations_page(pool: &State<DbPool>) -> Template {
+    let scores = db::get_hallucination_scores(pool).unwrap_or_default();
+    let quality = db::get_response_quality(pool).unwrap_or_default();
     
     Template::render("hallucinations", context! {
         title: "Hallucination Filter",
@@ -78,8 +79,8 @@ pub fn hallucinations_page() -> Template {
 }
 
 #[get("/providers")]
-pub fn providers_page() -> Template {
-    let providers = db::get_provider_performance().unwrap_or_default();
+pub fn providers_page(pool: &State<DbPool>) -> Template {
+    let providers = db::get_provider_performance(pool).unwrap_or_default();
     
     Template::render("providers", context! {
         title: "Provider Performance",
@@ -92,8 +93,8 @@ pub fn providers_page() -> Template {
 // =====================
 
 #[get("/stats")]
-pub fn api_stats() -> Json<DashboardStats> {
-    let stats = db::get_dashboard_stats().unwrap_or_else(|_| DashboardStats {
+pub fn api_stats(pool: &State<DbPool>) -> Json<DashboardStats> {
+    let stats = db::get_dashboard_stats(pool).unwrap_or_else(|_| DashboardStats {
         total_brands: 0,
         total_responses: 0,
         total_mentions: 0,
@@ -111,27 +112,27 @@ pub fn api_stats() -> Json<DashboardStats> {
 }
 
 #[get("/brands")]
-pub fn api_brands() -> Json<Vec<String>> {
-    let brands = db::get_all_brands().unwrap_or_default();
+pub fn api_brands(pool: &State<DbPool>) -> Json<Vec<String>> {
+    let brands = db::get_all_brands(pool).unwrap_or_default();
     Json(brands)
 }
 
 #[get("/rankings")]
-pub fn api_rankings() -> Json<Vec<BrandRanking>> {
-    let rankings = db::get_brand_rankings().unwrap_or_default();
+pub fn api_rankings(pool: &State<DbPool>) -> Json<Vec<BrandRanking>> {
+    let rankings = db::get_brand_rankings(pool).unwrap_or_default();
     Json(rankings)
 }
 
 #[get("/providers")]
-pub fn api_providers() -> Json<Vec<ProviderPerformance>> {
-    let providers = db::get_provider_performance().unwrap_or_default();
+pub fn api_providers(pool: &State<DbPool>) -> Json<Vec<ProviderPerformance>> {
+    let providers = db::get_provider_performance(pool).unwrap_or_default();
     Json(providers)
 }
 
 #[get("/graph?<min_strength>")]
-pub fn api_graph(min_strength: Option<f64>) -> Json<CompetitorGraph> {
+pub fn api_graph(min_strength: Option<f64>, pool: &State<DbPool>) -> Json<CompetitorGraph> {
     let strength = min_strength.unwrap_or(0.0);
-    let graph = db::get_competitor_graph(strength).unwrap_or_else(|_| CompetitorGraph {
+    let graph = db::get_competitor_graph(strength, pool).unwrap_or_else(|_| CompetitorGraph {
         nodes: vec![],
         edges: vec![],
         total_nodes: 0,
@@ -142,46 +143,71 @@ pub fn api_graph(min_strength: Option<f64>) -> Json<CompetitorGraph> {
 }
 
 #[get("/competitors/<brand>?<top_n>")]
-pub fn api_brand_competitors(brand: &str, top_n: Option<i32>) -> Json<Vec<CompetitorRelationship>> {
+pub fn api_brand_competitors(brand: &str, top_n: Option<i32>, pool: &State<DbPool>) -> Json<Vec<CompetitorRelationship>> {
     let n = top_n.unwrap_or(5);
-    let competitors = db::get_brand_competitors(brand, n).unwrap_or_default();
+    let competitors = db::get_brand_competitors(brand, n, pool).unwrap_or_default();
     Json(competitors)
 }
 
+#[get("/competitors/<brand>/cluster?<max_depth>&<min_strength>")]
+pub fn api_competitor_cluster(
+    brand: &str,
+    max_depth: Option<u32>,
+    min_strength: Option<f64>,
+    pool: &State<DbPool>,
+) -> Json<CompetitorGraph> {
+    let depth = max_depth.unwrap_or(2);
+    let strength = min_strength.unwrap_or(0.0);
+    let edges = db::get_all_competitor_relationships(pool).unwrap_or_default();
+    let cluster = graph::expand_cluster(brand, &edges, depth, strength);
+    Json(cluster)
+}
+
+#[get("/categories/rollup?<prefix>")]
+pub fn api_category_rollup(prefix: &str, pool: &State<DbPool>) -> Json<Option<CategoryRollup>> {
+    Json(db::get_category_rollup(prefix, pool).ok())
+}
+
+#[get("/categories/tree?<prefix>")]
+pub fn api_category_tree(prefix: Option<&str>, pool: &State<DbPool>) -> Json<Vec<CategoryNode>> {
+    let prefix = prefix.unwrap_or("");
+    Json(db::get_category_tree(prefix, pool).unwrap_or_default())
+}
+
 #[get("/hallucinations")]
-pub fn api_hallucinations() -> Json<Vec<HallucinationScore>> {
-    let scores = db::get_hallucination_scores().unwrap_or_default();
+pub fn api_hallucinations(pool: &State<DbPool>) -> Json<Vec<HallucinationScore>> {
+    let scores = db::get_hallucination_scores(pool).unwrap_or_default();
     Json(scores)
 }
 
 #[get("/quality")]
-pub fn api_quality() -> Json<Vec<ResponseQuality>> {
-    let quality = db::get_response_quality().unwrap_or_default();
+pub fn api_quality(pool: &State<DbPool>) -> Json<Vec<ResponseQuality>> {
+    let quality = db::get_response_quality(pool).unwrap_or_default();
     Json(quality)
 }
 
 #[get("/sources/<mention_id>")]
-pub fn api_sources(mention_id: i64) -> Json<Vec<Source>> {
-    let sources = db::get_sources_for_mention(mention_id).unwrap_or_default();
+pub fn api_sources(mention_id: i64, pool: &State<DbPool>) -> Json<Vec<Source>> {
+    let sources = db::get_sources_for_mention(mention_id, pool).unwrap_or_default();
     Json(sources)
 }
 
 #[get("/mentions?<limit>")]
-pub fn api_mentions(limit: Option<i32>) -> Json<Vec<BrandMention>> {
+pub fn api_mentions(limit: Option<i32>, pool: &State<DbPool>) -> Json<Vec<BrandMention>> {
     let l = limit.unwrap_or(50);
-    let mentions = db::get_recent_mentions(l).unwrap_or_default();
+    let mentions = db::get_recent_mentions(l, pool).unwrap_or_default();
     Json(mentions)
 }
 
 #[get("/evaluation-stats")]
-pub fn api_evaluation_stats() -> Json<Vec<EvaluationStats>> {
-    let stats = db::get_evaluation_stats().unwrap_or_default();
+pub fn api_evaluation_stats(pool: &State<DbPool>) -> Json<Vec<EvaluationStats>> {
+    let stats = db::get_evaluation_stats(pool).unwrap_or_default();
     Json(stats)
 }
 
 #[get("/confidence-distribution")]
-pub fn api_confidence_distribution() -> Json<Vec<(String, i32)>> {
-    let distribution = db::get_confidence_distribution().unwrap_or_default();
+pub fn api_confidence_distribution(pool: &State<DbPool>) -> Json<Vec<(String, i32)>> {
+    let distribution = db::get_confidence_distribution(pool).unwrap_or_default();
     Json(distribution)
 }
 
@@ -207,6 +233,9 @@ pub fn api_routes() -> Vec<Route> {
         api_providers,
         api_graph,
         api_brand_competitors,
+        api_competitor_cluster,
+        api_category_rollup,
+        api_category_tree,
         api_hallucinations,
         api_quality,
         api_sources,
@@ -0,0 +1,82 @@
+use crate::models::CategoryNode;
+
+/// Maximum number of dot-separated segments `is_valid_path` accepts in a
+/// category path.
+const MAX_PATH_DEPTH: usize = 16;
+
+/// `true` if `path` is syntactically well-formed: non-empty, dot-separated
+/// segments that are themselves non-empty and within `MAX_PATH_DEPTH`.
+/// Ordinary identifier characters (including `_`) are allowed — callers that
+/// bind a path into a SQL `LIKE` pattern are responsible for escaping it
+/// there, rather than this function banning names like `enterprise_software`.
+pub fn is_valid_path(path: &str) -> bool {
+    if path.is_empty() {
+        return false;
+    }
+    let segments: Vec<&str> = path.split('.').collect();
+    segments.len() <= MAX_PATH_DEPTH && segments.iter().all(|s| !s.is_empty())
+}
+
+/// `true` if `path` is under `prefix` in the materialized-path tree: either
+/// equal to `prefix` or nested beneath it at a `.` boundary, so `"tech.cloud"`
+/// matches `"tech.cloud.storage"` but not `"tech.clouds"`.
+pub fn is_descendant(path: &str, prefix: &str) -> bool {
+    if prefix.is_empty() {
+        return true;
+    }
+    path == prefix || path.starts_with(&format!("{prefix}."))
+}
+
+/// `true` if linking `path` under `parent` would introduce a cycle — i.e.
+/// `parent` is `path` itself or already nested beneath it.
+pub fn would_cycle(path: &str, parent: &str) -> bool {
+    is_descendant(parent, path)
+}
+
+/// Filter `nodes` down to those under `prefix`, inclusive of `prefix` itself.
+pub fn descendants<'a>(nodes: &'a [CategoryNode], prefix: &str) -> Vec<&'a CategoryNode> {
+    nodes
+        .iter()
+        .filter(|node| is_descendant(&node.path, prefix))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_empty_path() {
+        assert!(!is_valid_path(""));
+    }
+
+    #[test]
+    fn allows_underscores_in_segments() {
+        assert!(is_valid_path("tech_cloud"));
+        assert!(is_valid_path("tech.enterprise_software"));
+    }
+
+    #[test]
+    fn rejects_paths_deeper_than_max() {
+        let deep = (0..20).map(|i| format!("seg{i}")).collect::<Vec<_>>().join(".");
+        assert!(!is_valid_path(&deep));
+    }
+
+    #[test]
+    fn accepts_well_formed_path() {
+        assert!(is_valid_path("tech.cloud.storage"));
+    }
+
+    #[test]
+    fn descendant_matches_exact_and_nested_but_not_siblings() {
+        assert!(is_descendant("tech.cloud", "tech.cloud"));
+        assert!(is_descendant("tech.cloud.storage", "tech.cloud"));
+        assert!(!is_descendant("tech.clouds", "tech.cloud"));
+    }
+
+    #[test]
+    fn would_cycle_detects_ancestor_as_parent() {
+        assert!(would_cycle("tech.cloud", "tech.cloud.storage"));
+        assert!(!would_cycle("tech.cloud", "tech.network"));
+    }
+}